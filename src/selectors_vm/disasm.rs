@@ -0,0 +1,86 @@
+//! Opt-in bytecode disassembler for the selector-matching VM, gated by the
+//! `disasm` cargo feature so it adds no cost to regular builds. See the
+//! `prelude` comment in `selectors_vm` for why that feature (and `std`) is
+//! never actually turned on in this checkout.
+//!
+//! Meant for tooling that needs to explain why a CSS selector does or
+//! doesn't match, not for the hot path in
+//! `SelectorMatchingVm::exec_for_start_tag`.
+
+use super::prelude::{format, String};
+use super::program::Program;
+use super::stack::Stack;
+use super::{Debug, Hash, SelectorMatchingVm};
+
+impl<P> Program<P>
+where
+    P: PartialEq + Eq + Copy + Debug + Hash + 'static,
+{
+    /// Renders the compiled bytecode as a human-readable listing.
+    ///
+    /// For every instruction address this prints the instruction's `Debug`
+    /// output and marks the addresses that are VM entry points with `>`, so
+    /// the listing can be read alongside [`Stack::disassemble`] to trace a
+    /// selector match. The per-instruction predicates and the resolved
+    /// `ExecutionBranch` (matched payloads, `jumps` vs `hereditary_jumps`,
+    /// target `AddressRange`) are not decoded separately; only what `Instr`'s
+    /// `Debug` output shows is printed.
+    pub fn disassemble(&self) -> String {
+        let mut out = String::new();
+
+        for (addr, instr) in self.instructions.iter().enumerate() {
+            let marker = if self.entry_points.contains(&addr) {
+                '>'
+            } else {
+                ' '
+            };
+
+            out.push_str(&format!("{marker} {addr:>4}: {instr:?}\n"));
+        }
+
+        out
+    }
+}
+
+impl<P> Stack<P>
+where
+    P: PartialEq + Eq + Copy + Debug + Hash + 'static,
+{
+    /// Renders the current stack frames, innermost last, so a live
+    /// [`SelectorMatchingVm`] dump can be read alongside
+    /// [`Program::disassemble`] when tracing the restore-point/bailout logic
+    /// in `exec_for_start_tag`. Each frame's pending `jumps` and
+    /// `hereditary_jumps` are printed as their target `AddressRange`s, not
+    /// just their counts, so they can be cross-referenced against the
+    /// bytecode listing.
+    pub fn disassemble(&self) -> String {
+        let mut out = String::new();
+
+        for (depth, item) in self.items().iter().enumerate() {
+            out.push_str(&format!(
+                "#{depth}: jumps={jumps:?} hereditary_jumps={hereditary_jumps:?} has_ancestor_with_hereditary_jumps={ancestor}\n",
+                jumps = item.jumps,
+                hereditary_jumps = item.hereditary_jumps,
+                ancestor = item.has_ancestor_with_hereditary_jumps,
+            ));
+        }
+
+        out
+    }
+}
+
+impl<P> SelectorMatchingVm<P>
+where
+    P: PartialEq + Eq + Copy + Debug + Hash + 'static,
+{
+    /// Combines [`Program::disassemble`] with the live [`Stack`] contents,
+    /// for tracing why a selector did or didn't match while stepping through
+    /// `exec_for_start_tag`.
+    pub fn disassemble(&self) -> String {
+        format!(
+            "{}\n--- stack ---\n{}",
+            self.program.disassemble(),
+            self.stack.disassemble()
+        )
+    }
+}
@@ -1,18 +1,47 @@
 mod ast;
 mod attribute_matcher;
 mod compiler;
+#[cfg(feature = "disasm")]
+mod disasm;
 mod error;
 mod parser;
 mod program;
 mod stack;
 
+// Internal `core`/`alloc` prelude for the selector-matching VM: everything in
+// this module and `disasm` resolves `Debug`/`Hash`/`String`/`format!` (and,
+// without `std`, `Box`) through here instead of importing `std` directly;
+// with `std`, `Box` comes from the standard prelude. This checkout has no
+// `Cargo.toml`, so neither the `std` feature nor `disasm` (below) is ever
+// declared or turned on, and there's no `#![no_std]` crate root or ported
+// `parser`/`compiler`/`stack`/`transform_stream` to go with it — this is the
+// VM-local half of a `no_std` port, not a working one on its own.
+#[cfg(feature = "std")]
+mod prelude {
+    pub use std::fmt::Debug;
+    pub use std::hash::Hash;
+    pub use std::{format, string::String};
+}
+
+#[cfg(not(feature = "std"))]
+mod prelude {
+    extern crate alloc;
+
+    pub use alloc::boxed::Box;
+    pub use alloc::{format, string::String};
+    pub use core::fmt::Debug;
+    pub use core::hash::Hash;
+}
+
+#[cfg(not(feature = "std"))]
+use self::prelude::Box;
+use self::prelude::{Debug, Hash};
+
 use self::program::AddressRange;
 use self::stack::StackDirective;
 use crate::html::{LocalName, Namespace};
 use crate::transform_stream::AuxStartTagInfo;
 use encoding_rs::Encoding;
-use std::fmt::Debug;
-use std::hash::Hash;
 
 pub use self::ast::*;
 pub use self::attribute_matcher::AttributeMatcher;